@@ -1,13 +1,17 @@
 use anyhow::Result;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+use thiserror::Error;
 use tokio::sync::mpsc;
-use tracing::info;
+use tracing::{info, warn};
 
 use pallet_prover_primitives::{LayoutSegment, Query};
 use sp_core::H256;
 
-use crate::Client;
+use crate::{AlloyProvider, Client};
 use alloy::{
     network::EthereumWallet,
     primitives::{Address, FixedBytes, U256},
@@ -22,7 +26,9 @@ sol! {
     "contracts/prover.json",
 }
 
-pub const GAS_LIMIT: u64 = 50_000_000;
+/// Percentage applied over a fresh `estimate_gas` result before submitting a transaction, so
+/// live gas usage slightly above the estimate doesn't cause it to run out of gas.
+pub const GAS_LIMIT_BUFFER_PERCENT: u64 = 120;
 
 /// Prover contract proof
 pub type Proof = Vec<u8>;
@@ -37,8 +43,268 @@ pub struct ResultSegment {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Default)]
 pub struct GluwaPublicProverContract {
     pub address: Address,
-    #[allow(dead_code)]
-    gas_limit: u64,
+}
+
+/// Wire format version `ProofEnvelope::to_bytes`/`from_bytes` currently read and write.
+const PROOF_ENVELOPE_VERSION: u8 = 1;
+
+/// A query proof plus the metadata needed to validate and price it, encoded as a single
+/// versioned, length-prefixed byte string so it round-trips to the `bytes` the contract's
+/// `submitQueryProof` accepts and back, the same way the wormhole wire format wraps a payload
+/// with a version byte and length-prefixed fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofEnvelope {
+    pub query_id: FixedBytes<32>,
+    pub proof: Proof,
+    pub result_segments: Vec<ResultSegment>,
+    pub checkpoint_commitment: Option<FixedBytes<32>>,
+    pub layout_commitment: Option<FixedBytes<32>>,
+}
+
+/// Errors from validating or decoding a `ProofEnvelope`.
+#[derive(Debug, Error)]
+pub enum ProofEnvelopeError {
+    #[error("unsupported proof envelope version {0}")]
+    UnsupportedVersion(u8),
+    #[error("proof envelope carries an empty proof")]
+    EmptyProof,
+    #[error("result segments must be sorted by offset and non-overlapping")]
+    UnsortedSegments,
+    #[error("proof envelope truncated while reading {0}")]
+    Truncated(&'static str),
+}
+
+impl ProofEnvelope {
+    /// Check the invariants the contract (and `from_bytes`) rely on: a non-empty proof, and
+    /// result segments sorted by offset with no two overlapping.
+    pub fn validate(&self) -> Result<(), ProofEnvelopeError> {
+        if self.proof.is_empty() {
+            return Err(ProofEnvelopeError::EmptyProof);
+        }
+
+        let sorted_non_overlapping = self.result_segments.windows(2).all(|pair| {
+            let end_of_first = pair[0].offset + U256::from(pair[0].abi_bytes.len());
+            end_of_first <= pair[1].offset
+        });
+
+        if !sorted_non_overlapping {
+            return Err(ProofEnvelopeError::UnsortedSegments);
+        }
+
+        Ok(())
+    }
+
+    /// Serialize to the versioned wire format: a version byte, `query_id`, the length-prefixed
+    /// proof bytes, the result segments (count-prefixed, each a fixed-size offset plus
+    /// length-prefixed bytes), then the two optional commitments (a presence byte, followed by
+    /// the commitment itself when present).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = vec![PROOF_ENVELOPE_VERSION];
+
+        out.extend_from_slice(self.query_id.as_slice());
+        write_length_prefixed(&mut out, &self.proof);
+
+        out.extend_from_slice(&(self.result_segments.len() as u32).to_be_bytes());
+        for segment in &self.result_segments {
+            out.extend_from_slice(&segment.offset.to_be_bytes::<32>());
+            write_length_prefixed(&mut out, &segment.abi_bytes);
+        }
+
+        write_optional_commitment(&mut out, self.checkpoint_commitment);
+        write_optional_commitment(&mut out, self.layout_commitment);
+
+        out
+    }
+
+    /// Parse the wire format written by `to_bytes`, then apply the same checks `validate` does
+    /// so a structurally-invalid envelope is rejected before it's ever sent on-chain.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProofEnvelopeError> {
+        let mut reader = ByteReader::new(bytes);
+
+        let version = reader.read_u8("version")?;
+        if version != PROOF_ENVELOPE_VERSION {
+            return Err(ProofEnvelopeError::UnsupportedVersion(version));
+        }
+
+        let query_id = FixedBytes::<32>::from_slice(reader.read_exact(32, "query_id")?);
+        let proof = reader.read_length_prefixed("proof")?.to_vec();
+
+        let segment_count = reader.read_u32("result_segments length")?;
+        let mut result_segments = Vec::with_capacity(segment_count as usize);
+        for _ in 0..segment_count {
+            let offset = U256::from_be_slice(reader.read_exact(32, "segment offset")?);
+            let abi_bytes = reader.read_length_prefixed("segment bytes")?.to_vec();
+            result_segments.push(ResultSegment { offset, abi_bytes });
+        }
+
+        let checkpoint_commitment = reader.read_optional_commitment("checkpoint_commitment")?;
+        let layout_commitment = reader.read_optional_commitment("layout_commitment")?;
+
+        let envelope = ProofEnvelope {
+            query_id,
+            proof,
+            result_segments,
+            checkpoint_commitment,
+            layout_commitment,
+        };
+
+        envelope.validate()?;
+
+        Ok(envelope)
+    }
+}
+
+fn write_length_prefixed(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn write_optional_commitment(out: &mut Vec<u8>, commitment: Option<FixedBytes<32>>) {
+    match commitment {
+        Some(commitment) => {
+            out.push(1);
+            out.extend_from_slice(commitment.as_slice());
+        }
+        None => out.push(0),
+    }
+}
+
+/// Cursor over a byte slice for `ProofEnvelope::from_bytes`, tracking a read position and
+/// naming the field being read so a truncated envelope's error points at where it ran out.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read_exact(&mut self, len: usize, field: &'static str) -> Result<&'a [u8], ProofEnvelopeError> {
+        let end = self.pos + len;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(ProofEnvelopeError::Truncated(field))?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self, field: &'static str) -> Result<u8, ProofEnvelopeError> {
+        Ok(self.read_exact(1, field)?[0])
+    }
+
+    fn read_u32(&mut self, field: &'static str) -> Result<u32, ProofEnvelopeError> {
+        let bytes = self.read_exact(4, field)?;
+        Ok(u32::from_be_bytes(
+            bytes.try_into().expect("read_exact(4) returns 4 bytes"),
+        ))
+    }
+
+    fn read_length_prefixed(&mut self, field: &'static str) -> Result<&'a [u8], ProofEnvelopeError> {
+        let len = self.read_u32(field)? as usize;
+        self.read_exact(len, field)
+    }
+
+    fn read_optional_commitment(
+        &mut self,
+        field: &'static str,
+    ) -> Result<Option<FixedBytes<32>>, ProofEnvelopeError> {
+        if self.read_u8(field)? == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(FixedBytes::<32>::from_slice(
+            self.read_exact(32, field)?,
+        )))
+    }
+}
+
+fn query_from_submitted(query_submitted: &CreditcoinPublicProver::QuerySubmitted) -> Query {
+    query_from_chain_query(&query_submitted.chainQuery)
+}
+
+fn query_from_chain_query(q: &CreditcoinPublicProver::ChainQuery) -> Query {
+    Query {
+        chain_id: q.chainId,
+        height: q.height,
+        index: q.index,
+        layout_segments: q
+            .layoutSegments
+            .iter()
+            .map(|l| LayoutSegment {
+                offset: l.offset,
+                size: l.size,
+            })
+            .collect::<Vec<_>>(),
+    }
+}
+
+fn query_from_unprocessed(q: CreditcoinPublicProver::ChainQuery) -> Query {
+    query_from_chain_query(&q)
+}
+
+/// An on-chain checkpoint bracketing a requested block height, and how far that height is
+/// from it. The contract charges more for proofs further from a checkpoint, since proving them
+/// means replaying more intermediate state.
+///
+/// `pallet_prover_primitives::Query` is defined upstream, outside this crate, so this distance
+/// can't live on `Query`/`ChainQuery` directly; instead
+/// `compute_query_cost` looks it up itself before pricing a query, so the cost it returns (and
+/// anything paid against that cost, e.g. `submit_query`'s `value`) already accounts for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub height: u64,
+    pub distance: u64,
+}
+
+/// Binary-search `checkpoints` (assumed sorted ascending) for the one closest to `height`,
+/// returning it along with the absolute block distance between them. With no checkpoints at
+/// all, `height` is trivially distance `0` from itself.
+fn nearest_checkpoint(checkpoints: &[u64], height: u64) -> Checkpoint {
+    let after = checkpoints.partition_point(|&checkpoint| checkpoint <= height);
+
+    let nearest = [after.checked_sub(1), Some(after).filter(|&i| i < checkpoints.len())]
+        .into_iter()
+        .flatten()
+        .map(|i| checkpoints[i])
+        .min_by_key(|&checkpoint| height.abs_diff(checkpoint))
+        .unwrap_or(height);
+
+    Checkpoint {
+        height: nearest,
+        distance: height.abs_diff(nearest),
+    }
+}
+
+fn result_segments(proof_verified: CreditcoinPublicProver::QueryProofVerified) -> Vec<ResultSegment> {
+    proof_verified
+        .resultSegments
+        .into_iter()
+        .map(|r| ResultSegment {
+            offset: r.offset,
+            abi_bytes: r.abiBytes.into(),
+        })
+        .collect()
+}
+
+/// Race a set of futures — one per quorum endpoint — and return the first to resolve
+/// successfully, discarding the rest; if every one of them fails, returns the
+/// last error. With a single future this just awaits it, so single-endpoint clients pay no
+/// extra cost. Used to broadcast a signed transaction to every endpoint of a quorum `Client`
+/// and accept the first endpoint that actually mines it.
+async fn first_ok<T>(futures: Vec<Pin<Box<dyn Future<Output = Result<T>> + Send>>>) -> Result<T> {
+    let mut futures = futures;
+
+    if futures.len() == 1 {
+        return futures.remove(0).await;
+    }
+
+    match futures_util::future::select_ok(futures).await {
+        Ok((value, _still_pending)) => Ok(value),
+        Err(e) => Err(e),
+    }
 }
 
 pub async fn deploy(
@@ -76,27 +342,38 @@ pub async fn deploy(
 
     Ok(GluwaPublicProverContract {
         address: *contract.address(),
-        gas_limit: GAS_LIMIT,
     })
 }
 
 pub fn new(address: String) -> Result<GluwaPublicProverContract> {
     Ok(GluwaPublicProverContract {
         address: address.parse()?,
-        gas_limit: GAS_LIMIT,
     })
 }
 
 impl GluwaPublicProverContract {
-    /// Compute the query cost
+    /// Find the checkpoint nearest `height` for `chain_id`, by fetching the contract's
+    /// checkpoint heights and binary-searching them for the bracketing pair.
+    async fn checkpoint_for(&self, client: &Client, chain_id: u64, height: u64) -> Result<Checkpoint> {
+        let provider = client.write_provider()?;
+        let contract = CreditcoinPublicProver::new(self.address, provider);
+
+        let checkpoints = contract.getCheckpoints(chain_id).call().await?._0;
+
+        Ok(nearest_checkpoint(&checkpoints, height))
+    }
+
+    /// Compute the query cost, priced higher the further `query.height` is from its nearest
+    /// on-chain checkpoint. On a quorum `Client` the `computeQueryCost` call is dispatched to
+    /// every configured endpoint and only returns once `client.quorum` of them agree on the
+    /// cost. The checkpoint heights that set the distance passed to it are read from the
+    /// primary endpoint alone (`checkpoint_for`) and are not reconciled across endpoints.
     pub async fn compute_query_cost(&self, client: &Client, query: Query) -> Result<u64> {
         info!("Computing query cost");
 
-        let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(client.get_signer()?))
-            .on_http(client.get_url());
-
-        let contract = CreditcoinPublicProver::new(self.address, provider.clone());
+        let checkpoint = self
+            .checkpoint_for(client, query.chain_id, query.height)
+            .await?;
 
         let query = CreditcoinPublicProver::ChainQuery {
             chainId: query.chain_id,
@@ -112,48 +389,146 @@ impl GluwaPublicProverContract {
                 .collect::<Vec<_>>(),
         };
 
-        // probably here we can pass another argument like distance to nearest
-        // checkpoint to be included in the cost calculations
-        // TODO: add distance to nearest checkpoint to the query
-        let builder = contract.computeQueryCost(query);
-        let cost = builder.call().await?._0;
+        let providers = client.write_providers()?;
+
+        if providers.len() == 1 {
+            let provider = providers.into_iter().next().expect("checked len == 1");
+            let contract = CreditcoinPublicProver::new(self.address, provider);
+            let cost = contract
+                .computeQueryCost(query, checkpoint.distance)
+                .call()
+                .await?
+                ._0;
+            return Ok(cost.to::<u64>());
+        }
 
-        let num: u64 = cost.to::<u64>();
+        let results = futures::future::join_all(providers.into_iter().map(|provider| {
+            let query = query.clone();
+            async move {
+                let contract = CreditcoinPublicProver::new(self.address, provider);
+                contract
+                    .computeQueryCost(query, checkpoint.distance)
+                    .call()
+                    .await
+                    .map(|result| result._0.to::<u64>())
+                    .map_err(|e| crate::Error::ClientError(e.into()))
+            }
+        }))
+        .await;
+
+        let cost = client.reconcile_quorum("query cost", results, |cost| *cost)?;
 
-        Ok(num)
+        Ok(cost)
     }
 
-    /// Submit query proof
-    pub async fn submit_query_proof(
-        &self,
-        client: &Client,
-        query_id: FixedBytes<32>,
-        proof: Proof,
-    ) -> Result<String> {
-        info!("Submitting query proof for query: {:?}", query_id);
+    /// Submit a query proof. `envelope` is validated (non-empty proof, sorted/non-overlapping
+    /// result segments) before anything is sent, so a malformed proof fails locally instead of
+    /// burning gas on a doomed transaction. On a quorum `Client` the signed transaction is
+    /// broadcast to every configured endpoint and the first one to accept and mine it wins.
+    pub async fn submit_query_proof(&self, client: &Client, envelope: ProofEnvelope) -> Result<String> {
+        envelope.validate()?;
 
-        let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(client.get_signer()?))
-            .on_http(client.get_url());
+        info!("Submitting query proof for query: {:?}", envelope.query_id);
 
-        let contract = CreditcoinPublicProver::new(self.address, provider.clone());
+        let query_id = envelope.query_id;
+        let proof: alloy::primitives::Bytes = envelope.to_bytes().into();
 
-        let tx_request = contract
-            .submitQueryProof(query_id, proof.into())
-            .into_transaction_request()
-            .gas_limit(self.gas_limit)
-            .max_fee_per_gas(5_000_000_000u128)
-            .max_priority_fee_per_gas(3_000_000_000u128);
+        let providers = client.write_providers()?;
+        let gas_price = client.gas_price().await?;
 
-        let result = provider
-            .send_transaction(tx_request)
-            .await?
-            .get_receipt()
-            .await?;
+        let attempts = providers
+            .into_iter()
+            .map(|provider| {
+                let proof = proof.clone();
+                Box::pin(async move {
+                    let contract = CreditcoinPublicProver::new(self.address, provider.clone());
+
+                    let tx_request = contract
+                        .submitQueryProof(query_id, proof)
+                        .into_transaction_request()
+                        .max_fee_per_gas(gas_price.max_fee_per_gas)
+                        .max_priority_fee_per_gas(gas_price.max_priority_fee_per_gas);
+
+                    let gas_limit =
+                        provider.estimate_gas(&tx_request).await? * GAS_LIMIT_BUFFER_PERCENT / 100;
+                    let tx_request = tx_request.gas_limit(gas_limit);
+
+                    let result = provider
+                        .send_transaction(tx_request)
+                        .await?
+                        .get_receipt()
+                        .await?;
+
+                    Ok(result.transaction_hash.to_string())
+                }) as Pin<Box<dyn Future<Output = Result<String>> + Send>>
+            })
+            .collect();
 
-        Ok(result.transaction_hash.to_string())
+        first_ok(attempts).await
     }
 
+    /// Fetch `QuerySubmitted` logs in `[from_block, to_block]`, reduced to `(Query, block
+    /// number, log index)`. On a quorum `Client` this is dispatched to every configured
+    /// endpoint and only returns once `client.quorum` of them agree (compared by debug
+    /// representation, since the decoded event type has no derived `Hash`).
+    async fn backfill_query_submissions(
+        &self,
+        client: &Client,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<(Query, u64, u64)>> {
+        let providers = client.read_providers();
+
+        let rows_from = |provider: AlloyProvider| {
+            let address = self.address;
+            async move {
+                let contract = CreditcoinPublicProver::new(address, provider);
+                let backfilled = contract
+                    .QuerySubmitted_filter()
+                    .from_block(from_block)
+                    .to_block(to_block)
+                    .query()
+                    .await?;
+
+                Ok::<_, anyhow::Error>(
+                    backfilled
+                        .into_iter()
+                        .map(|(query_submitted, log)| {
+                            (
+                                query_from_submitted(&query_submitted),
+                                log.block_number.unwrap_or(from_block),
+                                log.log_index.unwrap_or_default(),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+
+        if providers.len() == 1 {
+            return rows_from(providers.into_iter().next().expect("checked len == 1")).await;
+        }
+
+        let results = futures::future::join_all(providers.into_iter().map(|provider| async {
+            rows_from(provider)
+                .await
+                .map_err(|e| crate::Error::ClientError(e))
+        }))
+        .await;
+
+        client.reconcile_quorum("query submissions backfill", results, |rows| format!("{rows:?}"))
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Subscribe to `QuerySubmitted` over a WS/IPC pub-sub connection, reconnecting on any
+    /// drop. On each (re)connect, past logs from the last block this subscriber processed up
+    /// to the current head are replayed first (deduplicated against the last log index it
+    /// already emitted) so an RPC hiccup can never skip or duplicate a query. On a quorum
+    /// `Client` the backfill is dispatched to every configured endpoint and only accepted once
+    /// `client.quorum` of them agree; the live stream itself still follows the single primary
+    /// endpoint, since reconciling a push subscription across endpoints event-by-event isn't
+    /// worth the added latency — any endpoint it drops to failing over is caught by the next
+    /// reconnect's quorum-backed backfill.
     pub async fn subscribe_query_submissions(
         &self,
         client: &Client,
@@ -164,51 +539,68 @@ impl GluwaPublicProverContract {
             self.address
         );
 
-        let provider = ProviderBuilder::new().on_http(client.get_url());
+        let mut last_block = client.get_last_block().await?;
+        let mut last_log_index = None;
 
-        let contract = CreditcoinPublicProver::new(self.address, provider.clone());
+        loop {
+            let ws = client.get_ws().await?;
+            let contract = CreditcoinPublicProver::new(self.address, ws);
 
-        let sub = contract.QuerySubmitted_filter().watch().await?;
-        let mut stream = sub.into_stream();
+            let head = client.get_last_block().await?;
+            if head > last_block {
+                let backfilled = self
+                    .backfill_query_submissions(client, last_block, head)
+                    .await?;
 
-        info!("Subscribed to query submissions");
+                for (query, block_number, log_index) in backfilled {
+                    if block_number < last_block
+                        || (block_number == last_block && Some(log_index) <= last_log_index)
+                    {
+                        continue;
+                    }
 
-        while let Some(query) = stream.next().await {
-            info!("New query submission");
-            let (query_submitted, _log) = query?;
+                    query_channel.send(query)?;
+                    last_block = block_number;
+                    last_log_index = Some(log_index);
+                }
 
-            // TODO: check log
+                last_block = head;
+            }
 
-            let query = Query {
-                chain_id: query_submitted.chainQuery.chainId,
-                height: query_submitted.chainQuery.height,
-                index: query_submitted.chainQuery.index,
-                layout_segments: query_submitted
-                    .chainQuery
-                    .layoutSegments
-                    .iter()
-                    .map(|l| LayoutSegment {
-                        offset: l.offset,
-                        size: l.size,
-                    })
-                    .collect::<Vec<_>>(),
-            };
+            let sub = contract.QuerySubmitted_filter().subscribe().await?;
+            let mut stream = sub.into_stream();
+
+            info!("Subscribed to query submissions");
+
+            loop {
+                match stream.next().await {
+                    Some(Ok((query_submitted, log))) => {
+                        info!("New query submission");
+
+                        last_block = log.block_number.unwrap_or(last_block);
+                        last_log_index = Some(log.log_index.unwrap_or_default());
+
+                        query_channel.send(query_from_submitted(&query_submitted))?;
+                    }
+                    Some(Err(e)) => {
+                        warn!("Query submission stream error: {e}; reconnecting");
+                        break;
+                    }
+                    None => {
+                        warn!("Query submission stream ended; reconnecting");
+                        break;
+                    }
+                }
+            }
 
-            query_channel.send(query)?;
+            tokio::time::sleep(Duration::from_secs(2)).await;
         }
-
-        Err(anyhow::anyhow!("Query submission stream ended"))
     }
 
+    /// Submit a query. On a quorum `Client` the signed transaction is broadcast to every
+    /// configured endpoint and the first one to accept it wins.
     pub async fn submit_query(&self, client: &Client, query: Query, cost: u64) -> Result<String> {
-        let signer = client.get_signer()?;
-        let principal = signer.address();
-
-        let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(signer))
-            .on_http(client.get_url());
-
-        let contract = CreditcoinPublicProver::new(self.address, provider);
+        let principal = client.get_signer()?.address();
 
         let query = CreditcoinPublicProver::ChainQuery {
             chainId: query.chain_id,
@@ -224,15 +616,85 @@ impl GluwaPublicProverContract {
                 .collect::<Vec<_>>(),
         };
 
-        let builder = contract
-            .submitQuery(query, principal)
-            .value(U256::from(cost));
+        let providers = client.write_providers()?;
+        let gas_price = client.gas_price().await?;
+
+        let attempts = providers
+            .into_iter()
+            .map(|provider| {
+                let query = query.clone();
+                Box::pin(async move {
+                    let contract = CreditcoinPublicProver::new(self.address, provider);
 
-        let result = builder.send().await?.watch().await?;
+                    let builder = contract
+                        .submitQuery(query, principal)
+                        .value(U256::from(cost))
+                        .max_fee_per_gas(gas_price.max_fee_per_gas)
+                        .max_priority_fee_per_gas(gas_price.max_priority_fee_per_gas);
 
-        Ok(result.to_string())
+                    let result = builder.send().await?.watch().await?;
+
+                    Ok(result.to_string())
+                }) as Pin<Box<dyn Future<Output = Result<String>> + Send>>
+            })
+            .collect();
+
+        first_ok(attempts).await
+    }
+
+    /// Fetch `QueryProofVerified` logs in `[from_block, to_block]` and return the result
+    /// segments for `query_id`, if it was verified in that range. On a quorum `Client` this is
+    /// dispatched to every configured endpoint and only returns once `client.quorum` of them
+    /// agree on the (possibly absent) result.
+    async fn backfill_proof_verification(
+        &self,
+        client: &Client,
+        query_id: FixedBytes<32>,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Option<Vec<ResultSegment>>> {
+        let providers = client.read_providers();
+
+        let found_from = |provider: AlloyProvider| async move {
+            let contract = CreditcoinPublicProver::new(self.address, provider);
+            let backfilled = contract
+                .QueryProofVerified_filter()
+                .from_block(from_block)
+                .to_block(to_block)
+                .query()
+                .await?;
+
+            Ok::<_, anyhow::Error>(
+                backfilled
+                    .into_iter()
+                    .find(|(proof_verified, _log)| proof_verified.queryId == query_id)
+                    .map(|(proof_verified, _log)| result_segments(proof_verified)),
+            )
+        };
+
+        if providers.len() == 1 {
+            return found_from(providers.into_iter().next().expect("checked len == 1")).await;
+        }
+
+        let results = futures::future::join_all(providers.into_iter().map(|provider| async {
+            found_from(provider)
+                .await
+                .map_err(|e| crate::Error::ClientError(e))
+        }))
+        .await;
+
+        client
+            .reconcile_quorum("proof verification backfill", results, |found| found.clone())
+            .map_err(anyhow::Error::from)
     }
 
+    /// Subscribe to `QueryProofVerified` over a WS/IPC pub-sub connection for `query_id`,
+    /// reconnecting on any drop. On each (re)connect, past logs from the last block this
+    /// subscriber processed up to the current head are replayed first, so an RPC hiccup can
+    /// never cause a matching proof to be missed. On a quorum `Client` the backfill is
+    /// dispatched to every configured endpoint and only accepted once `client.quorum` of them
+    /// agree; the live stream itself still follows the single primary endpoint, for the same
+    /// reason `subscribe_query_submissions` does.
     pub async fn subscribe_proof_verification(
         &self,
         client: &Client,
@@ -243,63 +705,93 @@ impl GluwaPublicProverContract {
             query_id
         );
 
-        let provider = ProviderBuilder::new().on_http(client.get_url());
+        let mut last_block = client.get_last_block().await?;
 
-        let contract = CreditcoinPublicProver::new(self.address, provider.clone());
+        loop {
+            let ws = client.get_ws().await?;
+            let contract = CreditcoinPublicProver::new(self.address, ws);
 
-        let sub = contract.QueryProofVerified_filter().watch().await?;
-        let mut stream = sub.into_stream();
+            let head = client.get_last_block().await?;
+            if head > last_block {
+                if let Some(segments) = self
+                    .backfill_proof_verification(client, query_id, last_block, head)
+                    .await?
+                {
+                    return Ok(segments);
+                }
 
-        info!("Subscribed to proof verification");
-
-        while let Some(proof) = stream.next().await {
-            let (proof_verified, _log) = proof?;
+                last_block = head;
+            }
 
-            if proof_verified.queryId == query_id {
-                return Ok(proof_verified
-                    .resultSegments
-                    .into_iter()
-                    .map(|r| ResultSegment {
-                        offset: r.offset,
-                        abi_bytes: r.abiBytes.into(),
-                    })
-                    .collect());
+            let sub = contract.QueryProofVerified_filter().subscribe().await?;
+            let mut stream = sub.into_stream();
+
+            info!("Subscribed to proof verification");
+
+            loop {
+                match stream.next().await {
+                    Some(Ok((proof_verified, log))) => {
+                        last_block = log.block_number.unwrap_or(last_block);
+
+                        if proof_verified.queryId == query_id {
+                            return Ok(result_segments(proof_verified));
+                        }
+                    }
+                    Some(Err(e)) => {
+                        warn!("Proof verification stream error: {e}; reconnecting");
+                        break;
+                    }
+                    None => {
+                        warn!("Proof verification stream ended; reconnecting");
+                        break;
+                    }
+                }
             }
-        }
 
-        Err(anyhow::anyhow!(
-            "Stream ended without matching proof verification"
-        ))
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
     }
 
+    /// Fetch the queries still awaiting a proof. On a quorum `Client` this is dispatched to
+    /// every configured endpoint and only returns once `client.quorum` of them agree on the
+    /// result (compared by its debug representation, since `Query` has no derived `Hash`).
     pub async fn get_unprocessed_queries(&self, client: &Client) -> Result<Vec<Query>> {
         info!("Getting unprocessed queries");
 
-        let provider = ProviderBuilder::new().on_http(client.get_url());
+        let providers = client.read_providers();
 
-        let contract = CreditcoinPublicProver::new(self.address, provider);
+        if providers.len() == 1 {
+            let provider = providers.into_iter().next().expect("checked len == 1");
+            let contract = CreditcoinPublicProver::new(self.address, provider);
+            let unprocessed = contract.getUnprocessedQueries().call().await?;
+            return Ok(unprocessed._0.into_iter().map(query_from_unprocessed).collect());
+        }
 
-        let unprocessed = contract.getUnprocessedQueries().call().await?;
+        let results = futures::future::join_all(providers.into_iter().map(|provider| async move {
+            let contract = CreditcoinPublicProver::new(self.address, provider);
+            contract
+                .getUnprocessedQueries()
+                .call()
+                .await
+                .map(|unprocessed| {
+                    unprocessed
+                        ._0
+                        .into_iter()
+                        .map(query_from_unprocessed)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(|e| crate::Error::ClientError(e.into()))
+        }))
+        .await;
 
-        Ok(unprocessed
-            ._0
-            .into_iter()
-            .map(|q| Query {
-                chain_id: q.chainId,
-                height: q.height,
-                index: q.index,
-                layout_segments: q
-                    .layoutSegments
-                    .iter()
-                    .map(|l| LayoutSegment {
-                        offset: l.offset,
-                        size: l.size,
-                    })
-                    .collect(),
-            })
-            .collect())
+        let queries =
+            client.reconcile_quorum("unprocessed queries", results, |queries| format!("{queries:?}"))?;
+
+        Ok(queries)
     }
 
+    /// On a quorum `Client` the signed transaction is broadcast to every configured endpoint
+    /// and the first one to accept it wins.
     pub async fn update_base_cost_per_bytes(
         &self,
         client: Client,
@@ -307,53 +799,85 @@ impl GluwaPublicProverContract {
     ) -> Result<String> {
         info!("Setting base cost per bytes: {}", new_cost_per_byte);
 
-        let signer = client.get_signer()?;
+        let providers = client.write_providers()?;
+        let gas_price = client.gas_price().await?;
 
-        let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(signer))
-            .on_http(client.get_url());
+        let attempts = providers
+            .into_iter()
+            .map(|provider| {
+                Box::pin(async move {
+                    let contract = CreditcoinPublicProver::new(self.address, provider);
 
-        let contract = CreditcoinPublicProver::new(self.address, provider);
+                    let builder = contract
+                        .updateCostPerByte(U256::from(new_cost_per_byte))
+                        .max_fee_per_gas(gas_price.max_fee_per_gas)
+                        .max_priority_fee_per_gas(gas_price.max_priority_fee_per_gas);
 
-        let builder = contract.updateCostPerByte(U256::from(new_cost_per_byte));
+                    let result = builder.send().await?.watch().await?;
 
-        let result = builder.send().await?.watch().await?;
+                    Ok(result.to_string())
+                }) as Pin<Box<dyn Future<Output = Result<String>> + Send>>
+            })
+            .collect();
 
-        Ok(result.to_string())
+        first_ok(attempts).await
     }
 
+    /// On a quorum `Client` the signed transaction is broadcast to every configured endpoint
+    /// and the first one to accept it wins.
     pub async fn update_base_fee(&self, client: Client, new_base_fee: u64) -> Result<String> {
         info!("Setting base fee: {}", new_base_fee);
 
-        let signer = client.get_signer()?;
+        let providers = client.write_providers()?;
+        let gas_price = client.gas_price().await?;
 
-        let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(signer))
-            .on_http(client.get_url());
+        let attempts = providers
+            .into_iter()
+            .map(|provider| {
+                Box::pin(async move {
+                    let contract = CreditcoinPublicProver::new(self.address, provider);
 
-        let contract = CreditcoinPublicProver::new(self.address, provider);
+                    let builder = contract
+                        .updateBaseFee(U256::from(new_base_fee))
+                        .max_fee_per_gas(gas_price.max_fee_per_gas)
+                        .max_priority_fee_per_gas(gas_price.max_priority_fee_per_gas);
 
-        let builder = contract.updateBaseFee(U256::from(new_base_fee));
+                    let result = builder.send().await?.watch().await?;
 
-        let result = builder.send().await?.watch().await?;
+                    Ok(result.to_string())
+                }) as Pin<Box<dyn Future<Output = Result<String>> + Send>>
+            })
+            .collect();
 
-        Ok(result.to_string())
+        first_ok(attempts).await
     }
 
+    /// On a quorum `Client` the signed transaction is broadcast to every configured endpoint
+    /// and the first one to accept it wins.
     pub async fn remove_query_id(&self, client: &Client, query_id: H256) -> Result<String> {
         info!("Removing query id: {:?}", query_id);
-        let signer = client.get_signer()?;
 
-        let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(signer))
-            .on_http(client.get_url());
+        let providers = client.write_providers()?;
+        let gas_price = client.gas_price().await?;
 
-        let contract = CreditcoinPublicProver::new(self.address, provider);
+        let attempts = providers
+            .into_iter()
+            .map(|provider| {
+                Box::pin(async move {
+                    let contract = CreditcoinPublicProver::new(self.address, provider);
 
-        let builder = contract.removeQueryId(query_id.0.into());
+                    let builder = contract
+                        .removeQueryId(query_id.0.into())
+                        .max_fee_per_gas(gas_price.max_fee_per_gas)
+                        .max_priority_fee_per_gas(gas_price.max_priority_fee_per_gas);
 
-        let result = builder.send().await?.get_receipt().await?;
+                    let result = builder.send().await?.get_receipt().await?;
+
+                    Ok(result.transaction_hash.to_string())
+                }) as Pin<Box<dyn Future<Output = Result<String>> + Send>>
+            })
+            .collect();
 
-        Ok(result.transaction_hash.to_string())
+        first_ok(attempts).await
     }
 }
@@ -1,28 +1,37 @@
 use alloy::{
     consensus::TxEnvelope,
-    network::Ethereum,
+    network::{Ethereum, EthereumWallet, TransactionBuilder},
     primitives::BlockHash,
     providers::{
-        fillers::{BlobGasFiller, ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller},
+        fillers::{
+            BlobGasFiller, ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller,
+            WalletFiller,
+        },
         network::TransactionResponse,
         Identity, Provider, ProviderBuilder, RootProvider,
     },
     rpc::{
         client::WsConnect,
         types::{
-            eth::{Block, BlockId, BlockNumberOrTag},
+            eth::{Block, BlockId, BlockNumberOrTag, TransactionRequest},
             ConversionError, Transaction, TransactionReceipt,
         },
     },
     signers::{k256::ecdsa::SigningKey, local::PrivateKeySigner},
-    transports::{http::reqwest::Url, TransportErrorKind},
+    transports::{http::reqwest::Url, RpcError, TransportErrorKind},
 };
 
 use anyhow::Result;
 use hex::FromHexError;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::mpsc::error::SendError;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use utils::{
     block_item_traits::{BlockItem, BlockItemIdentifier},
     StarknetPedersenMerkleTree,
@@ -31,6 +40,8 @@ use utils::{
 pub use alloy::core::primitives::Address;
 
 pub mod evm;
+pub mod logs;
+pub mod merkle;
 pub mod subscription;
 
 #[derive(Debug, Error)]
@@ -58,11 +69,19 @@ pub enum Error {
     #[error("Failed to get sync info")]
     FailedToGetSyncInfo,
     #[error("Failed to send block on channel")]
-    SendError(#[from] SendError<OrderedBlock>),
+    SendError(#[from] SendError<subscription::BlockEvent>),
     #[error("No Wallet configured")]
     NoWalletConfigured,
     #[error("Hex decoding error {0}")]
     HexDecodingError(#[from] FromHexError),
+    #[error("Reorg too deep to reconcile within the tracked head buffer")]
+    ReorgTooDeep,
+    #[error("Quorum not reached for {0}: no {1} endpoints agreed")]
+    QuorumNotReached(String, usize),
+    #[error("Transaction {0} dropped from the mempool")]
+    TransactionDropped(BlockHash),
+    #[error("Gas escalation cap of {0} attempts reached without a mined transaction")]
+    EscalationCapReached(u32),
 }
 
 #[derive(Debug)]
@@ -121,14 +140,17 @@ pub struct OrderedBlock {
     chain_id: u64,
     number: u64,
     hash: BlockHash,
+    parent_hash: BlockHash,
     items: Vec<TxRx>,
 }
 
 impl OrderedBlock {
+    #[allow(clippy::too_many_arguments)]
     pub fn try_create(
         chain_id: u64,
         number: u64,
         hash: BlockHash,
+        parent_hash: BlockHash,
         mut transactions: Vec<Transaction>,
         mut receipts: Vec<TransactionReceipt>,
     ) -> Result<Self, ConversionError> {
@@ -152,6 +174,7 @@ impl OrderedBlock {
             chain_id,
             number,
             hash,
+            parent_hash,
             items,
         })
     }
@@ -164,6 +187,9 @@ impl OrderedBlock {
     pub fn hash(&self) -> Option<BlockHash> {
         Some(self.hash)
     }
+    pub fn parent_hash(&self) -> BlockHash {
+        self.parent_hash
+    }
     pub fn items(&self) -> &[TxRx] {
         &self.items[..]
     }
@@ -173,15 +199,18 @@ pub struct OrderedRawBlock {
     pub chain_id: Option<u64>,
     pub number: u64,
     pub hash: BlockHash,
+    pub parent_hash: BlockHash,
     pub transactions: Vec<Transaction>,
     pub receipts: Vec<TransactionReceipt>,
 }
 
 impl OrderedRawBlock {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_id: Option<u64>,
         number: u64,
         hash: BlockHash,
+        parent_hash: BlockHash,
         mut transactions: Vec<Transaction>,
         mut receipts: Vec<TransactionReceipt>,
     ) -> Self {
@@ -192,6 +221,7 @@ impl OrderedRawBlock {
             chain_id,
             number,
             hash,
+            parent_hash,
             transactions,
             receipts,
         }
@@ -205,6 +235,160 @@ pub(crate) type ExeFiller = JoinFill<
     JoinFill<GasFiller, JoinFill<BlobGasFiller, JoinFill<NonceFiller, ChainIdFiller>>>,
 >;
 
+/// The write-path provider: the same filler stack as `AlloyProvider`, plus a wallet layer.
+/// Its `NonceFiller` tracks the next nonce locally, so sharing one long-lived instance (via
+/// `Client::write_provider`) across concurrent sends is what keeps them from reusing a nonce.
+pub(crate) type WriteFiller = JoinFill<ExeFiller, WalletFiller<EthereumWallet>>;
+pub(crate) type WriteProvider = FillProvider<WriteFiller, RootProvider<Ethereum>, Ethereum>;
+
+/// Retry policy for transient RPC failures, applied by `Client::with_retry` around the
+/// `Client`'s block, receipt, block-number, chain-id, fee-history and nonce reads.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of attempts (including the first) before giving up.
+    pub max_attempts: u32,
+    /// Backoff before the first retry; doubled on each subsequent retryable failure.
+    pub initial_backoff: Duration,
+    /// Ceiling applied to the (possibly rate-limit-driven) backoff.
+    pub max_backoff: Duration,
+    /// Total time budget across all attempts; exceeding it gives up even if attempts remain.
+    pub max_elapsed: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            max_elapsed: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Classify a transport error as retryable, returning a suggested delay before the next
+/// attempt (honoring rate-limit-style responses), or `None` for terminal errors.
+fn retryable_delay(err: &RpcError<TransportErrorKind>) -> Option<Duration> {
+    match err {
+        RpcError::Transport(TransportErrorKind::HttpError(http_err)) => match http_err.status {
+            429 => Some(Duration::from_secs(1)),
+            500..=599 => Some(Duration::from_millis(250)),
+            _ => None,
+        },
+        RpcError::Transport(TransportErrorKind::BackendGone)
+        | RpcError::Transport(TransportErrorKind::PubsubUnavailable) => {
+            Some(Duration::from_millis(250))
+        }
+        RpcError::Transport(TransportErrorKind::Custom(_)) => {
+            // reqwest surfaces connection resets and timeouts as opaque custom errors.
+            let message = err.to_string().to_lowercase();
+            (message.contains("timed out")
+                || message.contains("timeout")
+                || message.contains("connection reset"))
+            .then_some(Duration::from_millis(250))
+        }
+        _ => None,
+    }
+}
+
+/// Spread a backoff over `[0, duration)` so that concurrent callers retrying after the same
+/// failure don't all hammer the node at once.
+fn jitter(duration: Duration) -> Duration {
+    let nanos = duration.as_nanos() as u64;
+    if nanos == 0 {
+        return duration;
+    }
+
+    let seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| u64::from(d.subsec_nanos()))
+        .unwrap_or_default();
+
+    Duration::from_nanos(seed % nanos)
+}
+
+/// Computes the next max fee per gas to rebroadcast with, from the previous max fee per gas
+/// and the one-based attempt number (`1` for the first rebroadcast). Mirrors ethers'
+/// `EscalationPolicy`.
+pub type EscalationPolicy = Arc<dyn Fn(u128, u32) -> u128 + Send + Sync>;
+
+/// Bumps the previous max fee per gas by 20% on every attempt, ignoring the attempt number.
+fn default_escalation_policy(previous_max_fee_per_gas: u128, _attempt: u32) -> u128 {
+    previous_max_fee_per_gas + previous_max_fee_per_gas / 5
+}
+
+/// Governs rebroadcast of a transaction that hasn't been mined promptly, applied by
+/// `Client::send_transaction`.
+#[derive(Clone)]
+pub struct GasEscalator {
+    /// Blocks to wait at the current fee before bumping and rebroadcasting.
+    pub blocks_per_attempt: u64,
+    /// Maximum number of rebroadcast attempts before giving up with `Error::EscalationCapReached`.
+    pub max_attempts: u32,
+    /// Computes the next max fee per gas from the previous one and the attempt number.
+    pub policy: EscalationPolicy,
+}
+
+impl std::fmt::Debug for GasEscalator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GasEscalator")
+            .field("blocks_per_attempt", &self.blocks_per_attempt)
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for GasEscalator {
+    fn default() -> Self {
+        Self {
+            blocks_per_attempt: 3,
+            max_attempts: 5,
+            policy: Arc::new(default_escalation_policy),
+        }
+    }
+}
+
+/// EIP-1559 fees suggested by `Client::gas_price`, in place of hardcoding a `max_fee_per_gas`
+/// and `max_priority_fee_per_gas`.
+#[derive(Debug, Clone, Copy)]
+pub struct GasEstimate {
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+/// Derives `GasEstimate`s from `eth_feeHistory`, as in the ethers gas-oracle middleware.
+#[derive(Debug, Clone)]
+pub struct GasOracle {
+    /// Number of trailing blocks to request history for.
+    pub block_count: u64,
+    /// Reward percentiles requested from the node; `priority_percentile_index` selects which
+    /// of these columns feeds `max_priority_fee_per_gas`.
+    pub reward_percentiles: Vec<f64>,
+    /// Index into `reward_percentiles` used as the priority-fee column.
+    pub priority_percentile_index: usize,
+    /// How long a fetched estimate stays cached before `gas_price` fetches a fresh one.
+    pub ttl: Duration,
+    /// Used in place of a live estimate when `eth_feeHistory` is unavailable. When it returns
+    /// base fees but no usable reward data, only the fallback's `max_priority_fee_per_gas` is
+    /// used, and `max_fee_per_gas` is still derived from the live base fee.
+    pub fallback: GasEstimate,
+}
+
+impl Default for GasOracle {
+    fn default() -> Self {
+        Self {
+            block_count: 10,
+            reward_percentiles: vec![10.0, 50.0, 90.0],
+            priority_percentile_index: 1,
+            ttl: Duration::from_secs(12),
+            fallback: GasEstimate {
+                max_fee_per_gas: 5_000_000_000,
+                max_priority_fee_per_gas: 3_000_000_000,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Client {
     url: Url,
@@ -214,6 +398,25 @@ pub struct Client {
     // what chain id is implied here? Maybe need to define internal chain ids for different attestation chains
     // and not rely on ethereum chain ids?
     chain_id: u64,
+    retry_config: RetryConfig,
+    // All endpoints backing this client. A plain `Client::new` is just `[url]` with `quorum`
+    // 1, which keeps the single-endpoint code path (and its error messages) unchanged.
+    peers: Arc<Vec<Url>>,
+    quorum: usize,
+    // Index into `peers` of the endpoint used for `get_url`/`renew_http`/`get_ws`. Shared
+    // across clones so every reader of a rotated client sees the same primary.
+    primary_index: Arc<AtomicUsize>,
+    // The wallet-backed provider behind `write_provider`, built on first use and then shared
+    // by every clone so their `NonceFiller`s track the same next-nonce state.
+    write_provider: Arc<std::sync::OnceLock<WriteProvider>>,
+    // The per-peer wallet-backed providers behind `write_providers`, built on first use and
+    // shared the same way as `write_provider` — otherwise every call in quorum mode would build
+    // fresh per-peer `NonceFiller`s and two concurrent writes could fetch and reuse the same
+    // on-chain nonce.
+    write_providers_cache: Arc<std::sync::OnceLock<Vec<WriteProvider>>>,
+    gas_oracle: GasOracle,
+    // Cached result of the last `eth_feeHistory` fetch, behind `gas_oracle.ttl`.
+    gas_cache: Arc<std::sync::Mutex<Option<(Instant, GasEstimate)>>>,
 }
 
 impl Client {
@@ -232,18 +435,110 @@ impl Client {
         })?;
 
         Ok(Self {
+            peers: Arc::new(vec![url.clone()]),
             url,
             private_key: private_key.map(|s| s.to_owned()),
             http,
             chain_id,
+            retry_config: RetryConfig::default(),
+            quorum: 1,
+            primary_index: Arc::new(AtomicUsize::new(0)),
+            write_provider: Arc::new(std::sync::OnceLock::new()),
+            write_providers_cache: Arc::new(std::sync::OnceLock::new()),
+            gas_oracle: GasOracle::default(),
+            gas_cache: Arc::new(std::sync::Mutex::new(None)),
         })
     }
 
+    /// Connect to multiple RPC endpoints for Byzantine-tolerant reads: `get_block` and
+    /// `get_raw_block` fan a request out to every endpoint and only accept a result once
+    /// `quorum` of them agree, guarding attestation against a single lagging or malicious
+    /// node. The first URL is the initial primary used by `get_url`/`renew_http`/`get_ws`.
+    pub async fn new_quorum(urls: &[&str], quorum: usize, private_key: Option<&str>) -> Result<Self> {
+        if urls.is_empty() {
+            return Err(anyhow::anyhow!(
+                "At least one RPC endpoint is required for a quorum client"
+            ));
+        }
+
+        let peers = urls
+            .iter()
+            .map(|u| Url::parse(u))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut client = Self::new(urls[0], private_key).await?;
+        client.peers = Arc::new(peers);
+        client.quorum = quorum.clamp(1, client.peers.len());
+
+        Ok(client)
+    }
+
     pub fn chain_id(&self) -> u64 {
         self.chain_id
     }
 
+    /// Override the default retry policy used for transient RPC failures.
+    #[must_use]
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Override the default gas oracle used by `gas_price`.
+    #[must_use]
+    pub fn with_gas_oracle(mut self, gas_oracle: GasOracle) -> Self {
+        self.gas_oracle = gas_oracle;
+        self
+    }
+
+    /// Run `f` against the node, retrying retryable transport failures (timeouts,
+    /// rate-limiting, 5xx, dropped connections) with jittered exponential backoff until the
+    /// configured attempt count or time budget is exhausted.
+    async fn with_retry<T, F, Fut>(&self, description: &str, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, RpcError<TransportErrorKind>>>,
+    {
+        let deadline = Instant::now() + self.retry_config.max_elapsed;
+        let mut backoff = self.retry_config.initial_backoff;
+        let mut attempt = 0u32;
+
+        loop {
+            attempt += 1;
+
+            match f().await {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let retry_after = retryable_delay(&err);
+                    let exhausted =
+                        retry_after.is_none() || attempt >= self.retry_config.max_attempts;
+
+                    if exhausted || Instant::now() >= deadline {
+                        return Err(Error::EthError(err));
+                    }
+
+                    // `retry_after` (e.g. the 1s suggested for a 429) is a delay the caller
+                    // asked us to honor, not just a hint to jitter away: full-jittering it down
+                    // toward 0 would let us immediately re-hammer a node that just rate-limited
+                    // us. Use it as a floor and jitter only the exponential backoff on top.
+                    let retry_after = retry_after.expect("exhausted already returned on None");
+                    let delay =
+                        (retry_after + jitter(backoff)).min(self.retry_config.max_backoff);
+
+                    warn!(
+                        "{description} failed on attempt {attempt} ({err}); retrying in {:?}",
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    backoff = (backoff * 2).min(self.retry_config.max_backoff);
+                }
+            }
+        }
+    }
+
     pub async fn renew_http(&mut self) -> Result<()> {
+        self.url = self.primary_url();
+
         let http = ProviderBuilder::new()
             .network::<Ethereum>()
             .on_http(self.url.clone());
@@ -254,11 +549,37 @@ impl Client {
 
     #[must_use]
     pub fn get_url(&self) -> Url {
-        self.url.clone()
+        self.primary_url()
+    }
+
+    /// The endpoint currently designated as primary.
+    fn primary_url(&self) -> Url {
+        let index = self.primary_index.load(Ordering::Relaxed) % self.peers.len();
+        self.peers[index].clone()
+    }
+
+    /// Advance the primary to the next configured endpoint, used to fail over away from a
+    /// peer that just failed a call.
+    fn rotate_primary(&self) {
+        if self.peers.len() <= 1 {
+            return;
+        }
+        self.primary_index.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Rotate away from the current primary if its fanned-out result (by position in
+    /// `self.peers`) came back an error, so `get_url`/`renew_http`/`get_ws` steer future
+    /// primary-only calls toward a peer that's actually responding.
+    fn fail_over_if_primary_errored<T>(&self, results: &[Result<T, Error>]) {
+        let index = self.primary_index.load(Ordering::Relaxed) % self.peers.len();
+        if results.get(index).is_some_and(Result::is_err) {
+            warn!("Primary endpoint {} failed; rotating", self.peers[index]);
+            self.rotate_primary();
+        }
     }
 
     pub async fn get_ws(&self) -> Result<AlloyProvider> {
-        let mut url = self.url.clone();
+        let mut url = self.primary_url();
 
         if url.scheme() == "http" {
             url.set_scheme("ws").map_err(|_| {
@@ -297,6 +618,254 @@ impl Client {
         Ok(PrivateKeySigner::from_signing_key(signing_key))
     }
 
+    /// The shared write-path provider: a wallet-backed provider over the primary endpoint,
+    /// built once and reused by the contract methods under `evm` (`send_transaction` builds
+    /// its own) so its `NonceFiller` tracks one next-nonce counter instead of each call
+    /// independently fetching the account nonce and racing concurrent submissions onto it.
+    pub(crate) fn write_provider(&self) -> Result<WriteProvider, Error> {
+        if let Some(provider) = self.write_provider.get() {
+            return Ok(provider.clone());
+        }
+
+        let provider = ProviderBuilder::new()
+            .wallet(EthereumWallet::from(self.get_signer()?))
+            .on_http(self.get_url());
+
+        Ok(self.write_provider.get_or_init(|| provider).clone())
+    }
+
+    /// One read-only provider per configured endpoint (or just the primary outside quorum
+    /// mode), for fanning a read call out across a quorum client without requiring a signer.
+    pub(crate) fn read_providers(&self) -> Vec<AlloyProvider> {
+        if self.peers.len() <= 1 {
+            return vec![self.http.clone()];
+        }
+
+        self.peers
+            .iter()
+            .map(|peer_url| {
+                ProviderBuilder::new()
+                    .network::<Ethereum>()
+                    .on_http(peer_url.clone())
+            })
+            .collect()
+    }
+
+    /// One wallet-backed provider per configured endpoint, for broadcasting a signed
+    /// transaction to every quorum endpoint (or reading through a wallet-backed call such as
+    /// `computeQueryCost`). In single-endpoint mode this is just `[write_provider()]`, so the
+    /// caller does the same amount of work as before. Built once and cached (like
+    /// `write_provider`) so every caller shares the same per-peer `NonceFiller`s instead of each
+    /// fetching the account nonce independently and racing concurrent sends onto it.
+    pub(crate) fn write_providers(&self) -> Result<Vec<WriteProvider>, Error> {
+        if self.peers.len() <= 1 {
+            return Ok(vec![self.write_provider()?]);
+        }
+
+        if let Some(providers) = self.write_providers_cache.get() {
+            return Ok(providers.clone());
+        }
+
+        let signer = self.get_signer()?;
+
+        let providers: Vec<WriteProvider> = self
+            .peers
+            .iter()
+            .map(|peer_url| {
+                ProviderBuilder::new()
+                    .wallet(EthereumWallet::from(signer.clone()))
+                    .on_http(peer_url.clone())
+            })
+            .collect();
+
+        Ok(self
+            .write_providers_cache
+            .get_or_init(|| providers)
+            .clone())
+    }
+
+    /// Suggest EIP-1559 fees from recent `eth_feeHistory`, caching the result for
+    /// `gas_oracle.ttl`. Falls back to `gas_oracle.fallback` if the node doesn't support
+    /// `eth_feeHistory`; if it returns no usable reward data, only the fallback priority fee is
+    /// used on top of the live base fee.
+    pub async fn gas_price(&self) -> Result<GasEstimate, Error> {
+        if let Some((fetched_at, estimate)) = *self
+            .gas_cache
+            .lock()
+            .expect("gas price cache lock poisoned")
+        {
+            if fetched_at.elapsed() < self.gas_oracle.ttl {
+                return Ok(estimate);
+            }
+        }
+
+        let estimate = match self.fetch_gas_price().await {
+            Ok(estimate) => estimate,
+            Err(e) => {
+                warn!("Falling back to static gas price: {e}");
+                self.gas_oracle.fallback
+            }
+        };
+
+        *self
+            .gas_cache
+            .lock()
+            .expect("gas price cache lock poisoned") = Some((Instant::now(), estimate));
+
+        Ok(estimate)
+    }
+
+    async fn fetch_gas_price(&self) -> Result<GasEstimate, Error> {
+        let history = self
+            .with_retry("fee_history", || {
+                self.http.get_fee_history(
+                    self.gas_oracle.block_count,
+                    BlockNumberOrTag::Latest,
+                    &self.gas_oracle.reward_percentiles,
+                )
+            })
+            .await?;
+
+        let base_fee = history
+            .base_fee_per_gas
+            .last()
+            .copied()
+            .ok_or_else(|| Error::ClientError(anyhow::anyhow!("feeHistory returned no base fees")))?;
+
+        let mut priority_fees = history
+            .reward
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|row| row.get(self.gas_oracle.priority_percentile_index).copied())
+            .filter(|fee| *fee > 0)
+            .collect::<Vec<_>>();
+        priority_fees.sort_unstable();
+
+        let max_priority_fee_per_gas = priority_fees
+            .get(priority_fees.len() / 2)
+            .copied()
+            .unwrap_or(self.gas_oracle.fallback.max_priority_fee_per_gas);
+
+        Ok(GasEstimate {
+            max_fee_per_gas: base_fee * 2 + max_priority_fee_per_gas,
+            max_priority_fee_per_gas,
+        })
+    }
+
+    /// Sign and broadcast `tx` with the configured wallet, filling in unset gas fields via the
+    /// provider's filler stack, then wait for a receipt. The nonce is not taken from a filler:
+    /// it is read from the node's transaction count for the signer on each call, so it is not
+    /// coordinated with `write_provider`'s nonce counter or with concurrent calls to this
+    /// method, which can read the same nonce. If `escalator` is
+    /// `Some` and the transaction isn't mined within `blocks_per_attempt` blocks, the same
+    /// nonce is rebroadcast with bumped fees (per `escalator.policy`) until it confirms or
+    /// `max_attempts` is exhausted, at which point `Error::EscalationCapReached` is returned.
+    pub async fn send_transaction(
+        &self,
+        tx: TransactionRequest,
+        escalator: Option<GasEscalator>,
+    ) -> Result<TransactionReceipt, Error> {
+        let signer = self.get_signer()?;
+        let address = signer.address();
+
+        let provider = ProviderBuilder::new()
+            .wallet(EthereumWallet::from(signer))
+            .on_http(self.get_url());
+
+        let nonce = self
+            .with_retry("get_transaction_count", || {
+                provider.get_transaction_count(address)
+            })
+            .await?;
+
+        let mut tx = tx.with_nonce(nonce);
+        let pending_hash = provider
+            .send_transaction(tx.clone())
+            .await
+            .map_err(Error::EthError)?
+            .tx_hash()
+            .to_owned();
+
+        let Some(escalator) = escalator else {
+            // A freshly-broadcast hash has no receipt yet; poll for one instead of checking
+            // once and reporting a just-submitted, perfectly valid transaction as dropped.
+            let deadline = Instant::now() + self.retry_config.max_elapsed;
+            loop {
+                if let Some(receipt) = provider
+                    .get_transaction_receipt(pending_hash)
+                    .await
+                    .map_err(Error::EthError)?
+                {
+                    return Ok(receipt);
+                }
+
+                if Instant::now() >= deadline {
+                    return Err(Error::TransactionDropped(pending_hash));
+                }
+
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        };
+
+        let mut tx_hash = pending_hash;
+        let mut attempt = 0u32;
+        // `tx.max_fee_per_gas` is usually still unset here: the provider's `GasFiller` fills it
+        // on the request it sends, not on our local copy, so seeding from
+        // `tx.max_fee_per_gas.unwrap_or_default()` would start escalation from zero. Seed from
+        // an explicit caller-set fee if there is one, otherwise from this client's own gas
+        // oracle (`gas_price`).
+        let mut max_fee_per_gas = match tx.max_fee_per_gas {
+            Some(fee) => fee,
+            None => self.gas_price().await?.max_fee_per_gas,
+        };
+
+        loop {
+            let submitted_at = self.get_last_block().await?;
+
+            loop {
+                if let Some(receipt) = provider
+                    .get_transaction_receipt(tx_hash)
+                    .await
+                    .map_err(Error::EthError)?
+                {
+                    return Ok(receipt);
+                }
+
+                if self.get_last_block().await? >= submitted_at + escalator.blocks_per_attempt {
+                    break;
+                }
+
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+
+            attempt += 1;
+            if attempt > escalator.max_attempts {
+                return Err(Error::EscalationCapReached(escalator.max_attempts));
+            }
+
+            max_fee_per_gas = (escalator.policy)(max_fee_per_gas, attempt);
+            let priority_fee = tx
+                .max_priority_fee_per_gas
+                .unwrap_or(max_fee_per_gas)
+                .min(max_fee_per_gas);
+
+            warn!(
+                "Transaction {} not mined after {} blocks; rebroadcasting at attempt {} with max fee {}",
+                tx_hash, escalator.blocks_per_attempt, attempt, max_fee_per_gas
+            );
+
+            tx = tx
+                .with_max_fee_per_gas(max_fee_per_gas)
+                .with_max_priority_fee_per_gas(priority_fee);
+
+            tx_hash = *provider
+                .send_transaction(tx.clone())
+                .await
+                .map_err(Error::EthError)?
+                .tx_hash();
+        }
+    }
+
     pub async fn get_block(&self, number: u64) -> Result<OrderedBlock, Error> {
         info!(
             "Getting block {:?}",
@@ -319,6 +888,7 @@ impl Client {
             self.chain_id,
             number,
             block.header.hash,
+            block.header.parent_hash,
             transactions,
             receipts,
         )
@@ -342,45 +912,132 @@ impl Client {
             Some(self.chain_id),
             number,
             block.header.hash,
+            block.header.parent_hash,
             transactions,
             receipts,
         ))
     }
 
     async fn get_receipts(&self, number: u64) -> Result<Vec<TransactionReceipt>, Error> {
-        self.http
-            .get_block_receipts(BlockId::Number(BlockNumberOrTag::Number(number)))
-            .await
-            .map_err(|e| {
-                error!("Failed to get receipts: {:?}", e);
-                Error::FailedToGetReceipts(number)
-            })?
-            .ok_or(Error::FailedToGetBlock(number))
+        if self.peers.len() <= 1 {
+            return self.get_receipts_from(&self.http, number).await;
+        }
+
+        let results = futures::future::join_all(self.peers.iter().map(|peer_url| async move {
+            let provider = ProviderBuilder::new()
+                .network::<Ethereum>()
+                .on_http(peer_url.clone());
+            self.get_receipts_from(&provider, number).await
+        }))
+        .await;
+
+        self.fail_over_if_primary_errored(&results);
+
+        self.reconcile_quorum(&format!("block {number} receipts"), results, |receipts| {
+            receipts
+                .iter()
+                .map(|rx| rx.transaction_hash)
+                .collect::<Vec<_>>()
+        })
+    }
+
+    async fn get_receipts_from(
+        &self,
+        provider: &AlloyProvider,
+        number: u64,
+    ) -> Result<Vec<TransactionReceipt>, Error> {
+        self.with_retry("get_block_receipts", || {
+            provider.get_block_receipts(BlockId::Number(BlockNumberOrTag::Number(number)))
+        })
+        .await
+        .map_err(|e| {
+            error!("Failed to get receipts: {:?}", e);
+            Error::FailedToGetReceipts(number)
+        })?
+        .ok_or(Error::FailedToGetBlock(number))
     }
 
     async fn get_eth_block(&self, number: u64) -> Result<Block, Error> {
-        self.http
-            .get_block(
+        if self.peers.len() <= 1 {
+            return self.get_eth_block_from(&self.http, number).await;
+        }
+
+        let results = futures::future::join_all(self.peers.iter().map(|peer_url| async move {
+            let provider = ProviderBuilder::new()
+                .network::<Ethereum>()
+                .on_http(peer_url.clone());
+            self.get_eth_block_from(&provider, number).await
+        }))
+        .await;
+
+        self.fail_over_if_primary_errored(&results);
+
+        self.reconcile_quorum(&format!("block {number}"), results, |block| block.header.hash)
+    }
+
+    async fn get_eth_block_from(&self, provider: &AlloyProvider, number: u64) -> Result<Block, Error> {
+        self.with_retry("get_block", || {
+            provider.get_block(
                 BlockId::Number(BlockNumberOrTag::Number(number)),
                 true.into(),
             )
-            .await
-            .map_err(|e| {
-                error!("Failed to get block: {:?}", e);
-                Error::FailedToGetBlock(number)
-            })?
-            .ok_or(Error::FailedToGetBlock(number))
+        })
+        .await
+        .map_err(|e| {
+            error!("Failed to get block: {:?}", e);
+            Error::FailedToGetBlock(number)
+        })?
+        .ok_or(Error::FailedToGetBlock(number))
+    }
+
+    /// Group per-endpoint results by `key_fn` and accept the first group that reaches
+    /// `self.quorum` agreeing responses; logs and discards per-endpoint failures along the
+    /// way. Used to make `get_eth_block`/`get_receipts`, and the `evm::prover` read
+    /// calls, Byzantine-tolerant against a single lagging or malicious endpoint when the
+    /// client is configured with `new_quorum`. `description` is only used for logging/errors
+    /// (e.g. `"block 123"` or `"query cost"`).
+    pub(crate) fn reconcile_quorum<T, K>(
+        &self,
+        description: &str,
+        results: Vec<Result<T, Error>>,
+        key_fn: impl Fn(&T) -> K,
+    ) -> Result<T, Error>
+    where
+        T: Clone,
+        K: Eq + Hash,
+    {
+        let mut groups: HashMap<K, (usize, T)> = HashMap::new();
+
+        for result in results {
+            match result {
+                Ok(value) => {
+                    let key = key_fn(&value);
+                    let entry = groups.entry(key).or_insert_with(|| (0, value));
+                    entry.0 += 1;
+                }
+                Err(e) => warn!("Quorum endpoint failed for {}: {}", description, e),
+            }
+        }
+
+        groups
+            .into_values()
+            .find(|(count, _)| *count >= self.quorum)
+            .map(|(_, value)| value)
+            .ok_or_else(|| Error::QuorumNotReached(description.to_owned(), self.quorum))
     }
 
     pub async fn get_last_block(&self) -> Result<u64, Error> {
-        Ok(self.http.get_block_number().await?)
+        self.with_retry("get_block_number", || self.http.get_block_number())
+            .await
     }
 
     pub async fn get_chain_id(&self) -> Result<u64, Error> {
-        self.http.get_chain_id().await.map_err(|e| {
-            error!("Failed to get chain id: {:?}", e);
-            Error::FailedToGetChainId
-        })
+        self.with_retry("get_chain_id", || self.http.get_chain_id())
+            .await
+            .map_err(|e| {
+                error!("Failed to get chain id: {:?}", e);
+                Error::FailedToGetChainId
+            })
     }
 }
 
@@ -0,0 +1,152 @@
+use alloy::primitives::{Address, BlockHash, B256};
+use alloy::rpc::types::Log;
+use alloy::sol_types::SolValue;
+use utils::{
+    block_item_traits::{BlockItem, BlockItemIdentifier},
+    StarknetPedersenMerkleTree,
+};
+
+use crate::{Client, Error, OrderedRawBlock};
+
+/// Filter selecting which contract events to extract from a block. Mirrors the shape of
+/// `eth_newFilter`, but is applied locally against logs already present in the receipts
+/// fetched by `Client::get_raw_block` rather than issued as a separate RPC call.
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    /// Only match logs emitted by one of these addresses; empty means any address.
+    pub addresses: Vec<Address>,
+    /// Only match logs carrying at least one of these topics; empty means any topic.
+    pub topics: Vec<B256>,
+    pub from_block: u64,
+    pub to_block: u64,
+}
+
+impl LogFilter {
+    fn matches(&self, number: u64, log: &Log) -> bool {
+        if number < self.from_block || number > self.to_block {
+            return false;
+        }
+
+        if !self.addresses.is_empty() && !self.addresses.contains(&log.address()) {
+            return false;
+        }
+
+        if !self.topics.is_empty() && !log.topics().iter().any(|topic| self.topics.contains(topic)) {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// A single contract event matched by a `LogFilter`, parallel to `TxRx` but carrying a raw
+/// `Log` instead of a transaction/receipt pair.
+#[derive(Debug)]
+pub struct LogItem {
+    id: BlockItemIdentifier,
+    log: Log,
+}
+
+impl LogItem {
+    pub fn log(&self) -> &Log {
+        &self.log
+    }
+}
+
+impl BlockItem for LogItem {
+    fn payload_bytes(&self) -> Vec<u8> {
+        (
+            self.log.address(),
+            self.log.topics().to_vec(),
+            self.log.data().data.clone(),
+        )
+            .abi_encode()
+    }
+
+    fn id(&self) -> &BlockItemIdentifier {
+        &self.id
+    }
+
+    fn tx_type(&self) -> Option<u8> {
+        None
+    }
+}
+
+/// A block reduced to only the logs matching a `LogFilter`, so the Pedersen MMR (and the
+/// proofs built over it) covers a single contract's events instead of the whole block.
+#[derive(Debug)]
+pub struct FilteredBlock {
+    chain_id: u64,
+    number: u64,
+    hash: BlockHash,
+    parent_hash: BlockHash,
+    items: Vec<LogItem>,
+}
+
+impl FilteredBlock {
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+    pub fn hash(&self) -> BlockHash {
+        self.hash
+    }
+    pub fn parent_hash(&self) -> BlockHash {
+        self.parent_hash
+    }
+    pub fn items(&self) -> &[LogItem] {
+        &self.items[..]
+    }
+}
+
+/// Build a Pedersen MMR over just the matched events of a `FilteredBlock`, mirroring
+/// `starknet_pedersen_mmr` for whole blocks.
+pub fn starknet_pedersen_mmr_filtered(block: &FilteredBlock) -> StarknetPedersenMerkleTree {
+    let abis = block
+        .items()
+        .iter()
+        .map(BlockItem::to_bytes)
+        .collect::<Vec<Vec<u8>>>();
+
+    StarknetPedersenMerkleTree::from(&abis[..])
+}
+
+impl Client {
+    /// Extract the logs in `raw` matching `filter`, in receipt order.
+    pub fn get_logs(&self, raw: &OrderedRawBlock, filter: &LogFilter) -> Vec<LogItem> {
+        if raw.number < filter.from_block || raw.number > filter.to_block {
+            return Vec::new();
+        }
+
+        raw.receipts
+            .iter()
+            .flat_map(|receipt| receipt.logs())
+            .filter(|log| filter.matches(raw.number, log))
+            .enumerate()
+            .map(|(index, log)| LogItem {
+                id: BlockItemIdentifier::new(raw.number, index as u64),
+                log: log.clone(),
+            })
+            .collect()
+    }
+
+    /// Fetch block `number` and reduce it to only the logs matching `filter`.
+    pub async fn get_filtered_block(
+        &self,
+        number: u64,
+        filter: &LogFilter,
+    ) -> Result<FilteredBlock, Error> {
+        let raw = self.get_raw_block(number).await?;
+        let items = self.get_logs(&raw, filter);
+
+        Ok(FilteredBlock {
+            chain_id: raw.chain_id.unwrap_or_else(|| self.chain_id()),
+            number: raw.number,
+            hash: raw.hash,
+            parent_hash: raw.parent_hash,
+            items,
+        })
+    }
+}
@@ -1,4 +1,9 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::logs::{FilteredBlock, LogFilter};
 use crate::{Client, OrderedBlock};
+use alloy::primitives::BlockHash;
 use alloy::providers::Provider;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -9,10 +14,24 @@ use tracing::{debug, info, warn};
 
 use crate::Error;
 
+/// Event emitted by a head subscription: either a new block to attest, or notice that a
+/// previously emitted range of blocks was orphaned by a reorg and must be invalidated and
+/// re-attested.
+#[derive(Debug)]
+pub enum BlockEvent {
+    Block(OrderedBlock),
+    /// A block reduced to only the logs matching the subscription's `LogFilter`; emitted
+    /// instead of `Block` when `open_subscription` was given a filter.
+    FilteredBlock(FilteredBlock),
+    /// The canonical chain changed between heights `from` and `to` (inclusive); anything
+    /// previously emitted in that range came from an orphaned fork.
+    Reorged { from: u64, to: u64 },
+}
+
 #[async_trait]
 pub trait BlockSubscription: Send + Sync {
     fn cancel(&self);
-    async fn next(&mut self) -> Result<Option<OrderedBlock>, Error>;
+    async fn next(&mut self) -> Result<Option<BlockEvent>, Error>;
 }
 
 const BUFFER_SIZE: usize = 100;
@@ -21,7 +40,7 @@ const BUFFER_SIZE: usize = 100;
 /// It subscribes to the head of the chain and pushes new blocks to the channel
 #[derive(Debug)]
 struct NewBlockSubscription {
-    receiver: mpsc::Receiver<OrderedBlock>,
+    receiver: mpsc::Receiver<BlockEvent>,
     handle: JoinHandle<Result<(), Error>>,
 }
 
@@ -40,10 +59,10 @@ impl BlockSubscription for NewBlockSubscription {
         self.handle.abort();
     }
 
-    /// Get the next block from the channel
-    async fn next(&mut self) -> Result<Option<OrderedBlock>, Error> {
+    /// Get the next block event from the channel
+    async fn next(&mut self) -> Result<Option<BlockEvent>, Error> {
         match self.receiver.recv().await {
-            Some(block) => Ok(Some(block)),
+            Some(event) => Ok(Some(event)),
             None => {
                 warn!("Channel closed; no more blocks will be received");
                 Ok(None)
@@ -52,11 +71,90 @@ impl BlockSubscription for NewBlockSubscription {
     }
 }
 
+/// How many recently delivered headers are tracked for reorg detection.
+const REORG_BUFFER_SIZE: usize = 64;
+
+/// A minimal descriptor of a delivered header, kept around just long enough to verify chain
+/// continuity against the next one.
+#[derive(Debug, Clone, Copy)]
+struct HeaderDescriptor {
+    number: u64,
+    hash: BlockHash,
+}
+
+/// Push a descriptor onto the bounded ring buffer, evicting the oldest entry once full.
+fn track_header(recent: &mut VecDeque<HeaderDescriptor>, descriptor: HeaderDescriptor) {
+    if recent.len() == REORG_BUFFER_SIZE {
+        recent.pop_front();
+    }
+    recent.push_back(descriptor);
+}
+
+/// Check a newly received header against the tracked chain of descriptors and, if its
+/// `parent_hash` doesn't line up with the last tracked hash, walk backwards re-fetching
+/// canonical blocks via `client` until the parent-hash chain reconciles.
+///
+/// Returns `Ok(Some(common_ancestor))` when a reorg was detected and reconciled down to the
+/// still-canonical height `common_ancestor`, `Ok(None)` when the header extends the tracked
+/// chain cleanly (or the buffer is empty, i.e. we just started and have nothing to compare
+/// against), and `Err(Error::ReorgTooDeep)` when the walk runs out of tracked headers, or
+/// reaches a height missing from the buffer, before reconciliation. Heights orphaned by an
+/// earlier reorg are popped here and their canonical replacements are not tracked, so they
+/// count as missing.
+async fn detect_and_reconcile_reorg(
+    client: &Client,
+    recent: &mut VecDeque<HeaderDescriptor>,
+    header_number: u64,
+    header_parent_hash: BlockHash,
+) -> Result<Option<u64>, Error> {
+    let Some(last) = recent.back().copied() else {
+        // Nothing tracked yet; buffer underflow at startup is a no-op.
+        return Ok(None);
+    };
+
+    if last.number != header_number.saturating_sub(1) || last.hash == header_parent_hash {
+        return Ok(None);
+    }
+
+    warn!(
+        "Reorg detected: block {} doesn't extend tracked head {}",
+        header_number, last.number
+    );
+
+    let mut expected_parent_hash = header_parent_hash;
+    let mut cursor = last.number;
+
+    loop {
+        let Some(desc) = recent.back().copied() else {
+            return Err(Error::ReorgTooDeep);
+        };
+
+        if desc.number != cursor {
+            return Err(Error::ReorgTooDeep);
+        }
+
+        if desc.hash == expected_parent_hash {
+            return Ok(Some(cursor));
+        }
+
+        recent.pop_back();
+
+        if cursor == 0 {
+            return Err(Error::ReorgTooDeep);
+        }
+
+        let canonical = client.get_block(cursor).await?;
+        expected_parent_hash = canonical.parent_hash();
+        cursor -= 1;
+    }
+}
+
 /// Subscribe to the latest heads of the chain
 /// This function returns a `BlockSubscription` trait object
 fn subscribe_latest_heads(
     client: Client,
     interval: u64,
+    filter: Option<LogFilter>,
 ) -> Result<Box<dyn BlockSubscription>, Error> {
     let (sender, receiver) = mpsc::channel(BUFFER_SIZE);
 
@@ -67,21 +165,51 @@ fn subscribe_latest_heads(
         // Open stream
         let mut stream = subscription.into_stream();
 
+        let mut recent: VecDeque<HeaderDescriptor> = VecDeque::with_capacity(REORG_BUFFER_SIZE);
+
         loop {
             if let Some(header) = stream.next().await {
                 let block_number = header.number;
 
                 debug!("Received block: {}", block_number);
+
+                if let Some(common_ancestor) =
+                    detect_and_reconcile_reorg(&client, &mut recent, block_number, header.parent_hash)
+                        .await?
+                {
+                    // `common_ancestor` is still canonical and was already validly emitted; the
+                    // orphaned range starts just above it and ends just below `block_number`,
+                    // which hasn't been emitted yet and will be delivered fresh right after this.
+                    sender
+                        .send(BlockEvent::Reorged {
+                            from: common_ancestor + 1,
+                            to: block_number - 1,
+                        })
+                        .await?;
+                }
+
+                track_header(
+                    &mut recent,
+                    HeaderDescriptor {
+                        number: block_number,
+                        hash: header.hash,
+                    },
+                );
+
                 // Skip blocks that are not at the interval
                 if block_number % interval != 0 {
                     debug!("Skipping block: {}", block_number);
                     continue;
                 }
 
-                let block = client.get_block(block_number).await?;
-
                 debug!("Sending block({}) to channel", block_number);
-                sender.send(block).await?;
+                let event = match &filter {
+                    Some(filter) => {
+                        BlockEvent::FilteredBlock(client.get_filtered_block(block_number, filter).await?)
+                    }
+                    None => BlockEvent::Block(client.get_block(block_number).await?),
+                };
+                sender.send(event).await?;
             } else {
                 info!("Subscription stream ended");
                 return Err(Error::EndOfSubscription);
@@ -92,19 +220,82 @@ fn subscribe_latest_heads(
     Ok(Box::new(NewBlockSubscription { receiver, handle }))
 }
 
+/// How often the polling subscription checks the node for a new head, absent a WebSocket
+/// push notification.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(6);
+
+/// Subscribe to the latest heads of the chain by polling `get_last_block` on a fixed interval
+/// instead of upgrading to a WebSocket connection.
+/// This mirrors `subscribe_latest_heads`, but is usable against HTTP-only RPC endpoints.
+fn subscribe_polling_heads(
+    client: Client,
+    interval: u64,
+    poll_interval: Duration,
+    filter: Option<LogFilter>,
+) -> Result<Box<dyn BlockSubscription>, Error> {
+    let (sender, receiver) = mpsc::channel(BUFFER_SIZE);
+
+    let client = client.clone();
+    let handle = tokio::spawn(async move {
+        let mut last_emitted = client.get_last_block().await?;
+
+        loop {
+            tokio::time::sleep(poll_interval).await;
+
+            let head = client.get_last_block().await?;
+            if head <= last_emitted {
+                debug!("Polling: no new head (last emitted: {})", last_emitted);
+                continue;
+            }
+
+            let mut block_number = last_emitted + 1;
+            while block_number <= head {
+                if block_number % interval == 0 {
+                    debug!("Polling: fetching block: {}", block_number);
+
+                    let event = match &filter {
+                        Some(filter) => BlockEvent::FilteredBlock(
+                            client.get_filtered_block(block_number, filter).await?,
+                        ),
+                        None => BlockEvent::Block(client.get_block(block_number).await?),
+                    };
+
+                    debug!("Sending block({}) to channel", block_number);
+                    sender.send(event).await?;
+                } else {
+                    debug!("Skipping block: {}", block_number);
+                }
+
+                block_number += 1;
+            }
+
+            last_emitted = head;
+        }
+    });
+
+    Ok(Box::new(NewBlockSubscription { receiver, handle }))
+}
+
 /// `BlockFetcher` is a struct that fetches blocks from a given height with a given interval
 struct BlockFetcher {
     pub client: Client,
     pub config: SubscriptionConfig,
     pub interval: u64,
+    pub filter: Option<LogFilter>,
 }
 
 impl BlockFetcher {
-    pub fn new(client: Client, config: SubscriptionConfig, interval: u64) -> Self {
+    pub fn new(
+        client: Client,
+        config: SubscriptionConfig,
+        interval: u64,
+        filter: Option<LogFilter>,
+    ) -> Self {
         Self {
             client,
             config,
             interval,
+            filter,
         }
     }
 }
@@ -113,7 +304,7 @@ impl BlockFetcher {
 impl BlockSubscription for BlockFetcher {
     fn cancel(&self) {}
 
-    async fn next(&mut self) -> Result<Option<OrderedBlock>, Error> {
+    async fn next(&mut self) -> Result<Option<BlockEvent>, Error> {
         // If we reached the end block, return EndOfSubscription error
         if self.config.start_block >= self.config.end_block {
             return Err(Error::EndOfSubscription);
@@ -123,13 +314,20 @@ impl BlockSubscription for BlockFetcher {
             "Blockfetcher: Fetching block at height: {}",
             self.config.start_block
         );
-        // Get the block at the current height
-        let block = self.client.get_block(self.config.start_block).await?;
+
+        let event = match &self.filter {
+            Some(filter) => BlockEvent::FilteredBlock(
+                self.client
+                    .get_filtered_block(self.config.start_block, filter)
+                    .await?,
+            ),
+            None => BlockEvent::Block(self.client.get_block(self.config.start_block).await?),
+        };
 
         // Increment the height
         self.config.start_block += self.interval;
 
-        Ok(Some(block))
+        Ok(Some(event))
     }
 }
 
@@ -143,6 +341,28 @@ pub struct SubscriptionConfig {
     pub end_block: u64,
 }
 
+/// How a live head subscription should reach the node: by upgrading to a WebSocket and
+/// pushing new headers, or by polling a plain HTTP endpoint on an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeadSubscriptionMode {
+    /// Subscribe over a WebSocket connection (`provider.subscribe_blocks()`).
+    WebSocket,
+    /// Poll `Client::get_last_block` on `DEFAULT_POLL_INTERVAL` and backfill any blocks
+    /// between the last emitted height and the new head.
+    Polling,
+}
+
+impl HeadSubscriptionMode {
+    /// Pick a mode from the client's configured URL scheme: `ws`/`wss` upgrade to a
+    /// WebSocket subscription, anything else (`http`/`https`) falls back to polling.
+    fn from_url(url: &alloy::transports::http::reqwest::Url) -> Self {
+        match url.scheme() {
+            "ws" | "wss" => Self::WebSocket,
+            _ => Self::Polling,
+        }
+    }
+}
+
 impl Client {
     // Open a subscription to the chain
     // This function returns a `BlockSubscription` trait object
@@ -150,16 +370,40 @@ impl Client {
     // - `interval`: The interval to fetch blocks
     // If no configuration is provided, it will subscribe to the latest heads
     // If a configuration is provided, it will fetch blocks from a specific height with a given interval & switch to latest heads if it's all caught up
+    //
+    // When fetching latest heads, the subscription transport (WebSocket push vs. HTTP
+    // polling) is auto-selected from the client's URL scheme; use `open_subscription_with_mode`
+    // to override that choice explicitly. Pass a `LogFilter` to have the subscription emit
+    // `BlockEvent::FilteredBlock`s (only the matched events) instead of whole blocks.
     pub fn open_subscription(
         &self,
         config: Option<SubscriptionConfig>,
         interval: u64,
+        filter: Option<LogFilter>,
+    ) -> Result<Box<dyn BlockSubscription>, Error> {
+        let mode = HeadSubscriptionMode::from_url(&self.get_url());
+        self.open_subscription_with_mode(config, interval, mode, filter)
+    }
+
+    /// Same as `open_subscription`, but lets the caller force the head subscription
+    /// transport instead of inferring it from the URL scheme.
+    pub fn open_subscription_with_mode(
+        &self,
+        config: Option<SubscriptionConfig>,
+        interval: u64,
+        mode: HeadSubscriptionMode,
+        filter: Option<LogFilter>,
     ) -> Result<Box<dyn BlockSubscription>, Error> {
         let client = self.clone();
         if let Some(config) = config {
-            Ok(Box::new(BlockFetcher::new(client, config, interval)))
+            Ok(Box::new(BlockFetcher::new(client, config, interval, filter)))
         } else {
-            Ok(subscribe_latest_heads(client, interval)?)
+            match mode {
+                HeadSubscriptionMode::WebSocket => subscribe_latest_heads(client, interval, filter),
+                HeadSubscriptionMode::Polling => {
+                    subscribe_polling_heads(client, interval, DEFAULT_POLL_INTERVAL, filter)
+                }
+            }
         }
     }
 }